@@ -0,0 +1,201 @@
+// Copyright 2022 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! On-disk HTTP cache for remote manifests and trust configuration, keyed by URL, so CI
+//! pipelines signing many assets against the same remote resource don't re-fetch it every run.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use anyhow::{bail, Context, Result};
+use reqwest::{
+    blocking::Client,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    StatusCode, Url,
+};
+use serde::{Deserialize, Serialize};
+
+/// Per-URL locks guarding `HttpCache::get`, keyed by cache entry path. `--jobs` can run several
+/// `sign_file` calls against the same `--manifest-url` concurrently, each opening its own
+/// `HttpCache`; without this, every worker would issue its own conditional GET and race on the
+/// same on-disk entry. Holding this lock for the duration of `get` serializes them so only one
+/// worker actually fetches and the rest reuse what it wrote.
+static ENTRY_LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+
+fn entry_lock(entry_path: &Path) -> Arc<Mutex<()>> {
+    let mut locks = ENTRY_LOCKS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap();
+    locks
+        .entry(entry_path.to_path_buf())
+        .or_insert_with(|| Arc::new(Mutex::new(())))
+        .clone()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+/// On-disk HTTP cache rooted in the platform cache dir (e.g. `~/.cache/c2patool/http` on
+/// Linux). Entries are keyed by the SHA-256 of the requested URL and store the response body
+/// alongside its `ETag`/`Last-Modified` headers for conditional revalidation.
+pub struct HttpCache {
+    dir: PathBuf,
+}
+
+impl HttpCache {
+    pub fn open() -> Result<Self> {
+        let dir = dirs::cache_dir()
+            .context("Could not determine the platform cache directory")?
+            .join("c2patool")
+            .join("http");
+
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Creating HTTP cache directory `{}`", dir.display()))?;
+
+        Ok(Self { dir })
+    }
+
+    /// Fetches `url`, honoring `offline` (serve only from cache, error if missing) and
+    /// `no_cache` (always refetch, ignoring and overwriting any cached entry).
+    pub fn get(&self, url: &Url, offline: bool, no_cache: bool) -> Result<String> {
+        let entry_path = self.entry_path(url);
+        // Held for the whole lookup+fetch+store sequence below, so concurrent `--jobs` workers
+        // requesting the same URL don't each issue their own fetch and clobber each other's
+        // writes to `entry_path`.
+        let _guard = entry_lock(&entry_path).lock().unwrap();
+
+        let cached = (!no_cache)
+            .then(|| fs::read_to_string(&entry_path).ok())
+            .flatten()
+            .and_then(|raw| serde_json::from_str::<CacheEntry>(&raw).ok());
+
+        if offline {
+            return cached
+                .map(|entry| entry.body)
+                .with_context(|| format!("`{url}` is not cached and --offline was specified"));
+        }
+
+        if no_cache {
+            let (body, etag, last_modified) = self.fetch(url, None, None)?;
+            self.store(&entry_path, &CacheEntry {
+                etag,
+                last_modified,
+                body: body.clone(),
+            })?;
+            return Ok(body);
+        }
+
+        match &cached {
+            Some(entry) => {
+                match self.fetch(url, entry.etag.as_deref(), entry.last_modified.as_deref()) {
+                    Ok((body, etag, last_modified)) => {
+                        self.store(&entry_path, &CacheEntry {
+                            etag,
+                            last_modified,
+                            body: body.clone(),
+                        })?;
+                        Ok(body)
+                    }
+                    Err(err) if err.downcast_ref::<NotModified>().is_some() => {
+                        Ok(entry.body.clone())
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            None => {
+                let (body, etag, last_modified) = self.fetch(url, None, None)?;
+                self.store(&entry_path, &CacheEntry {
+                    etag,
+                    last_modified,
+                    body: body.clone(),
+                })?;
+                Ok(body)
+            }
+        }
+    }
+
+    /// Issues a conditional GET. Returns `Err(NotModified)` on a `304` response.
+    fn fetch(
+        &self,
+        url: &Url,
+        etag: Option<&str>,
+        last_modified: Option<&str>,
+    ) -> Result<(String, Option<String>, Option<String>)> {
+        let client = Client::new();
+        let mut req = client.get(url.clone());
+        if let Some(etag) = etag {
+            req = req.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = last_modified {
+            req = req.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let response = req
+            .send()
+            .with_context(|| format!("Fetching `{url}`"))?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            bail!(NotModified);
+        }
+
+        if !response.status().is_success() {
+            bail!("`{url}` returned HTTP {}", response.status());
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let last_modified = response
+            .headers()
+            .get(LAST_MODIFIED)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        let body = response.text().with_context(|| format!("Reading body of `{url}`"))?;
+
+        Ok((body, etag, last_modified))
+    }
+
+    fn store(&self, entry_path: &Path, entry: &CacheEntry) -> Result<()> {
+        fs::write(entry_path, serde_json::to_string(entry)?)
+            .with_context(|| format!("Writing cache entry `{}`", entry_path.display()))
+    }
+
+    fn entry_path(&self, url: &Url) -> PathBuf {
+        use sha2::{Digest, Sha256};
+        let digest = Sha256::digest(url.as_str().as_bytes());
+        let key: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+#[derive(Debug)]
+struct NotModified;
+
+impl std::fmt::Display for NotModified {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "304 Not Modified")
+    }
+}
+
+impl std::error::Error for NotModified {}
@@ -0,0 +1,155 @@
+// Copyright 2022 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Shared CLI plumbing used by more than one subcommand: the `Trust` argument group, the
+//! `InputSource` abstraction for a value that may be a local path or a remote URL, and
+//! `load_trust_settings`, which feeds both into the `c2pa` crate's trust store.
+
+pub mod sign;
+pub mod trust;
+pub mod view;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use reqwest::{blocking::Client, Url};
+
+pub use sign::Sign;
+pub use trust::TrustCmd;
+use trust::TrustStore;
+pub use view::View;
+
+use crate::cache::HttpCache;
+
+/// Trust anchors/config accepted by `sign` and `view`. Each may be a local path or a URL; if
+/// `--trust-anchors` is omitted entirely, the persistent local trust store of anchor
+/// certificates managed by `trust add`/`ls`/`rm` is used instead, so `sign`/`view` can work
+/// against a curated local anchor set without repeating `--trust-anchors` on every invocation.
+/// `--trust-config`/`--allowed-list` have no equivalent store and must still be passed
+/// explicitly (or via their `C2PATOOL_TRUST_*` env vars) whenever they're needed.
+#[derive(Debug, Args)]
+pub struct Trust {
+    /// Path or URL to a file containing the set of trust anchors in PEM format.
+    #[clap(long, env = "C2PATOOL_TRUST_ANCHORS")]
+    pub trust_anchors: Option<String>,
+
+    /// Path or URL to a file containing the set of allowed certificate hashes, one per line.
+    #[clap(long, env = "C2PATOOL_TRUST_ALLOWED_LIST")]
+    pub allowed_list: Option<String>,
+
+    /// Path or URL to the trust store configuration, defaults to the C2PA default trust config.
+    #[clap(long, env = "C2PATOOL_TRUST_CONFIG")]
+    pub trust_config: Option<String>,
+}
+
+/// A value accepted as either a local path or a URL, e.g. `--manifest`/`--manifest-url` or a
+/// trust anchor given to `trust add`.
+pub enum InputSource {
+    Path(PathBuf),
+    Url(Url),
+}
+
+impl InputSource {
+    /// Builds an `InputSource` from a pair of mutually exclusive optional arguments, exactly one
+    /// of which must be set.
+    pub fn from_path_or_url(path: Option<&Path>, url: Option<&Url>) -> Result<Self> {
+        match (path, url) {
+            (Some(path), None) => Ok(InputSource::Path(path.to_path_buf())),
+            (None, Some(url)) => Ok(InputSource::Url(url.clone())),
+            _ => bail!("Must specify exactly one of a path or a URL"),
+        }
+    }
+
+    /// Reads the source's contents: the file as-is for a path, or a GET for a URL.
+    pub fn resolve(&self) -> Result<String> {
+        match self {
+            InputSource::Path(path) => {
+                fs::read_to_string(path).with_context(|| format!("Reading `{}`", path.display()))
+            }
+            InputSource::Url(url) => {
+                let response = Client::new()
+                    .get(url.clone())
+                    .send()
+                    .with_context(|| format!("Fetching `{url}`"))?;
+                response
+                    .text()
+                    .with_context(|| format!("Reading body of `{url}`"))
+            }
+        }
+    }
+}
+
+/// Resolves a `--trust-anchors`/`--allowed-list`/`--trust-config` value: a local path is read
+/// directly, a URL is fetched through the on-disk HTTP cache (honoring `offline`/`no_cache`, the
+/// same as `sign`'s `--manifest-url`) so repeated runs against the same remote trust
+/// configuration don't refetch it every time.
+fn resolve_trust_source(value: &str, offline: bool, no_cache: bool) -> Result<String> {
+    if Path::new(value).is_file() {
+        return fs::read_to_string(value).with_context(|| format!("Reading `{value}`"));
+    }
+
+    let url: Url = value
+        .parse()
+        .with_context(|| format!("`{value}` is neither an existing file nor a valid URL"))?;
+
+    HttpCache::open()?.get(&url, offline, no_cache)
+}
+
+/// Loads trust anchors/allowed-list/config from `trust` into the `c2pa` crate's trust settings.
+/// `offline`/`no_cache` govern HTTP cache behavior for any of the three that are remote URLs. If
+/// `--trust-anchors` isn't given at all (neither flag nor `C2PATOOL_TRUST_ANCHORS`), falls back
+/// to the persistent local trust store of anchor certificates managed by `trust add`/`ls`/`rm`.
+/// `allowed_list`/`trust_config` have no such fallback: the store only ever holds anchors, so
+/// both remain `None` unless passed explicitly.
+pub fn load_trust_settings(trust: &Trust, offline: bool, no_cache: bool) -> Result<()> {
+    let trust_anchors = match &trust.trust_anchors {
+        Some(value) => Some(resolve_trust_source(value, offline, no_cache)?),
+        None => TrustStore::open()?
+            .anchors_path_if_present()
+            .map(|path| {
+                fs::read_to_string(&path)
+                    .with_context(|| format!("Reading trust store at `{}`", path.display()))
+            })
+            .transpose()?,
+    };
+
+    let allowed_list = trust
+        .allowed_list
+        .as_deref()
+        .map(|value| resolve_trust_source(value, offline, no_cache))
+        .transpose()?;
+
+    let trust_config = trust
+        .trust_config
+        .as_deref()
+        .map(|value| resolve_trust_source(value, offline, no_cache))
+        .transpose()?;
+
+    if trust_anchors.is_none() && allowed_list.is_none() && trust_config.is_none() {
+        return Ok(());
+    }
+
+    let settings = serde_json::json!({
+        "trust": {
+            "trust_anchors": trust_anchors,
+            "allowed_list": allowed_list,
+            "trust_config": trust_config,
+        }
+    });
+
+    c2pa::settings::load_settings_from_str(&settings.to_string(), "json")
+        .context("Loading trust settings")
+}
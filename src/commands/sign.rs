@@ -16,16 +16,26 @@ use std::{
     fs::{self, File},
     io::BufReader,
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
 };
 
 use anyhow::{bail, Context, Result};
-use c2pa::{Ingredient, Manifest};
+use c2pa::{
+    assertions::{Actions, CreativeWork, Exif},
+    Ingredient, Manifest,
+};
 use clap::{Args, Parser};
 use log::{error, warn};
 use reqwest::Url;
 use serde::Deserialize;
+use walkdir::WalkDir;
 
 use crate::{
+    cache::HttpCache,
     callback_signer::{CallbackSigner, CallbackSignerConfig, ExternalProcessRunner},
     commands::{load_trust_settings, InputSource, Trust},
     signer::SignConfig,
@@ -33,7 +43,9 @@ use crate::{
 
 #[derive(Debug, Parser)]
 pub struct Sign {
-    /// Input path(s) to asset(s).
+    /// Input path(s) to asset(s). Accepts literal paths, glob patterns (e.g. `images/**/*.jpg`),
+    /// and directories (walked recursively). Glob/directory expansion skips files whose
+    /// extension isn't a recognized asset type; a literal path is never filtered.
     pub paths: Vec<PathBuf>,
 
     /// Path to output file or folder (if >1 path specified).
@@ -85,6 +97,120 @@ pub struct Sign {
 
     #[clap(flatten)]
     pub trust: Trust,
+
+    /// Number of assets to sign in parallel, defaults to the number of available CPUs.
+    #[clap(short, long)]
+    pub jobs: Option<usize>,
+
+    /// Continue signing remaining assets if one fails instead of aborting immediately.
+    #[clap(long)]
+    pub keep_going: bool,
+
+    /// Serve remote manifests and trust anchors/config only from the local HTTP cache, erroring
+    /// if nothing is cached.
+    #[clap(long, conflicts_with = "no_cache")]
+    pub offline: bool,
+
+    /// Always refetch remote manifests and trust anchors/config instead of reusing the local
+    /// HTTP cache.
+    #[clap(long)]
+    pub no_cache: bool,
+
+    /// Assertion to merge into the manifest, repeatable: `label=@file.json` to load the
+    /// assertion data from a file, or `label={"some":"json"}` to pass it inline. `label` must be
+    /// a known C2PA assertion label (e.g. `c2pa.actions`, `stds.exif`) or a namespaced custom
+    /// label (e.g. `org.example.foo`).
+    #[clap(long = "assertion", value_name = "label=@file.json|label={json}")]
+    pub assertions: Vec<AssertionArg>,
+}
+
+/// A single `--assertion label=@file.json` / `--assertion label={json}` argument, parsed into
+/// the assertion label and its JSON data.
+#[derive(Debug, Clone)]
+pub struct AssertionArg {
+    pub label: String,
+    pub json: serde_json::Value,
+}
+
+// Assertion labels defined by the C2PA spec that `--assertion` is commonly used to inject;
+// anything else must look like a namespaced custom label (e.g. `org.example.foo`). Thumbnail
+// labels are deliberately not included here: a real thumbnail assertion carries binary image
+// data + format, not freeform JSON, which `--assertion`'s `label=@file.json|label={json}` form
+// can't express.
+const KNOWN_ASSERTION_LABELS: &[&str] = &[
+    "c2pa.actions",
+    "c2pa.actions.v2",
+    "stds.exif",
+    "stds.schema-org.CreativeWork",
+];
+
+// A namespaced custom label needs at least 3 non-empty, reverse-DNS-style segments (e.g.
+// `org.example.foo`); this only checks shape, not that the namespace is one we recognize.
+fn looks_like_namespaced_label(label: &str) -> bool {
+    let segments: Vec<&str> = label.split('.').collect();
+    segments.len() >= 3
+        && segments
+            .iter()
+            .all(|segment| !segment.is_empty() && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+}
+
+impl std::str::FromStr for AssertionArg {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (label, value) = s.split_once('=').with_context(|| {
+            format!("Assertion `{s}` must be in the form label=@file.json or label={{json}}")
+        })?;
+
+        if !KNOWN_ASSERTION_LABELS.contains(&label) && !looks_like_namespaced_label(label) {
+            bail!(
+                "`{label}` is not a known C2PA assertion label or a namespaced custom label (e.g. `org.example.foo`)"
+            );
+        }
+
+        let json = match value.strip_prefix('@') {
+            Some(path) => {
+                let contents = fs::read_to_string(path)
+                    .with_context(|| format!("Reading assertion file `{path}`"))?;
+                serde_json::from_str(&contents)
+                    .with_context(|| format!("Parsing assertion file `{path}` as JSON"))?
+            }
+            None => serde_json::from_str(value)
+                .with_context(|| format!("Parsing inline assertion for `{label}` as JSON"))?,
+        };
+
+        validate_known_assertion_shape(label, &json)?;
+
+        Ok(AssertionArg {
+            label: label.to_string(),
+            json,
+        })
+    }
+}
+
+// Rejects a known assertion label (one with a real `c2pa::assertions` type) whose JSON doesn't
+// deserialize into it, so a malformed `--assertion` payload fails at CLI-parsing time with a
+// clear message instead of deep inside the SDK during signing. Custom labels have no type to
+// check against and pass through unvalidated.
+fn validate_known_assertion_shape(label: &str, json: &serde_json::Value) -> Result<()> {
+    match label {
+        "c2pa.actions" | "c2pa.actions.v2" => {
+            serde_json::from_value::<Actions>(json.clone())
+                .with_context(|| format!("`{label}` assertion data is not a valid c2pa.actions assertion"))?;
+        }
+        "stds.exif" => {
+            serde_json::from_value::<Exif>(json.clone())
+                .with_context(|| format!("`{label}` assertion data is not a valid stds.exif assertion"))?;
+        }
+        "stds.schema-org.CreativeWork" => {
+            serde_json::from_value::<CreativeWork>(json.clone()).with_context(|| {
+                format!("`{label}` assertion data is not a valid stds.schema-org.CreativeWork assertion")
+            })?;
+        }
+        _ => {}
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Args)]
@@ -115,11 +241,19 @@ enum IngredientSource {
     Path(PathBuf),
 }
 
+// A single input expanded from a glob pattern or directory, paired with the path (relative to
+// its originating pattern's base directory) to preserve under a folder output.
+struct ExpandedInput {
+    path: PathBuf,
+    relative: PathBuf,
+}
+
 impl Sign {
     pub fn execute(&self) -> Result<()> {
-        let is_output_dir = self.validate()?;
+        let inputs = self.expand_paths()?;
+        let is_output_dir = self.validate(&inputs)?;
 
-        load_trust_settings(&self.trust)?;
+        load_trust_settings(&self.trust, self.offline, self.no_cache)?;
 
         let replacement_val = serde_json::Value::Bool(!self.no_verify).to_string();
         let vs = r#"{"verify": { "verify_after_sign": replacement_val } }"#;
@@ -127,22 +261,75 @@ impl Sign {
 
         c2pa::settings::load_settings_from_str(&setting, "json")?;
 
+        // Shared setup (trust settings, verify-after-sign toggle) is done above, once, before
+        // the pool starts. Everything below is dispatched to a bounded pool of worker threads;
+        // per-file work (InputSource::resolve, manifest parsing, signing) happens inside each
+        // worker so nothing non-Send needs to cross the thread boundary.
+        let jobs = self
+            .jobs
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+            .max(1)
+            .min(inputs.len().max(1));
+
+        let next_index = AtomicUsize::new(0);
+        let aborted = AtomicBool::new(false);
+        let results: Mutex<Vec<Option<Result<()>>>> =
+            Mutex::new((0..inputs.len()).map(|_| None).collect());
+
         // In the c2pa unstable_api we will be able to reuse a lot of this work rather than
         // reconstructing the entire manifest each iteration.
-        let mut errs = Vec::new();
-        for src in &self.paths {
-            let dst = match is_output_dir {
-                true => {
-                    // It's safe to unwrap because we already validated this in the beginning of the function.
-                    &self.output.join(src.file_name().unwrap())
-                }
-                false => &self.output,
-            };
+        thread::scope(|scope| {
+            for _ in 0..jobs {
+                scope.spawn(|| loop {
+                    if !self.keep_going && aborted.load(Ordering::Relaxed) {
+                        break;
+                    }
 
-            if let Err(err) = self.sign_file(src, dst) {
+                    let idx = next_index.fetch_add(1, Ordering::Relaxed);
+                    let Some(input) = inputs.get(idx) else {
+                        break;
+                    };
+
+                    let dst = match is_output_dir {
+                        // Preserve the relative subdirectory structure of glob/directory
+                        // expansions under the output folder rather than flattening by
+                        // `file_name()` only.
+                        true => self.output.join(&input.relative),
+                        false => self.output.clone(),
+                    };
+
+                    // Only a folder output preserves glob/directory expansion's subdirectory
+                    // structure and so needs its parent created; a single-file `-o` follows the
+                    // same cp-like philosophy as `validate()` above and must already exist.
+                    if is_output_dir {
+                        if let Some(parent) = dst.parent() {
+                            if let Err(err) = fs::create_dir_all(parent) {
+                                results.lock().unwrap()[idx] = Some(Err(err.into()));
+                                aborted.store(true, Ordering::Relaxed);
+                                continue;
+                            }
+                        }
+                    }
+
+                    let result = self.sign_file(&input.path, &dst);
+                    if result.is_err() {
+                        aborted.store(true, Ordering::Relaxed);
+                    }
+
+                    results.lock().unwrap()[idx] = Some(result);
+                });
+            }
+        });
+
+        let mut errs = Vec::new();
+        for (input, result) in inputs.iter().zip(results.into_inner().unwrap()) {
+            // A `None` entry means the job pool stopped before reaching this path because
+            // `--keep-going` was not set and an earlier asset failed; that isn't itself a
+            // failure to report.
+            if let Some(Err(err)) = result {
                 error!(
                     "Failed to sign asset at path `{}`, {}",
-                    src.display(),
+                    input.path.display(),
                     err.to_string()
                 );
                 errs.push(err);
@@ -150,12 +337,69 @@ impl Sign {
         }
 
         if !errs.is_empty() {
-            bail!("Failed to sign {}/{} assets", errs.len(), self.paths.len());
+            bail!("Failed to sign {}/{} assets", errs.len(), inputs.len());
         }
 
         Ok(())
     }
 
+    // Expands each of `self.paths` into concrete files: glob patterns (e.g. `images/**/*.jpg`)
+    // are matched recursively, directories are walked recursively, skipping anything that isn't
+    // a file, and literal paths pass through unchanged. The returned relative path is used to
+    // preserve subdirectory structure under a folder output.
+    fn expand_paths(&self) -> Result<Vec<ExpandedInput>> {
+        let mut inputs = Vec::new();
+
+        for pattern in &self.paths {
+            let pattern_str = pattern
+                .to_str()
+                .with_context(|| format!("Input path `{}` is not valid UTF-8", pattern.display()))?;
+
+            if is_glob_pattern(pattern_str) {
+                let base = glob_base_dir(pattern);
+                for entry in glob::glob(pattern_str)
+                    .with_context(|| format!("Invalid glob pattern `{pattern_str}`"))?
+                {
+                    let path = entry?;
+                    if !path.is_file() || !is_asset_file(&path) {
+                        continue;
+                    }
+
+                    let relative = path.strip_prefix(&base).unwrap_or(&path).to_path_buf();
+                    inputs.push(ExpandedInput { path, relative });
+                }
+            } else if pattern.is_dir() {
+                for entry in WalkDir::new(pattern) {
+                    let entry = entry?;
+                    if !entry.file_type().is_file() || !is_asset_file(entry.path()) {
+                        continue;
+                    }
+
+                    let relative = entry
+                        .path()
+                        .strip_prefix(pattern)
+                        .unwrap_or(entry.path())
+                        .to_path_buf();
+                    inputs.push(ExpandedInput {
+                        path: entry.into_path(),
+                        relative,
+                    });
+                }
+            } else {
+                let file_name = pattern.file_name().with_context(|| {
+                    format!("Input path `{}` has no file name", pattern.display())
+                })?;
+                inputs.push(ExpandedInput {
+                    path: pattern.clone(),
+                    relative: PathBuf::from(file_name),
+                });
+            }
+        }
+
+        inputs.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(inputs)
+    }
+
     fn sign_file(&self, src: &Path, dst: &Path) -> Result<()> {
         // Safe to unwrap because we know at least one of the fields are required.
         let input_source = InputSource::from_path_or_url(
@@ -163,7 +407,13 @@ impl Sign {
             self.manifest_source.manifest_url.as_ref(),
         )
         .unwrap();
-        let json = input_source.resolve()?;
+        let json = match &input_source {
+            // Remote manifests are re-downloaded on every invocation otherwise; route them
+            // through the on-disk HTTP cache so repeated CI runs against the same URL only
+            // revalidate with a conditional GET instead of refetching the whole body.
+            InputSource::Url(url) => HttpCache::open()?.get(url, self.offline, self.no_cache)?,
+            InputSource::Path(_) => input_source.resolve()?,
+        };
         // read the signing information from the manifest definition
         let mut sign_config = SignConfig::from_json(&json)?;
 
@@ -171,6 +421,20 @@ impl Sign {
         let ext_manifest: ExtendedManifest = serde_json::from_str(&json)?;
         let mut manifest = ext_manifest.manifest;
 
+        // merge any `--assertion label=...` arguments additively with whatever the manifest
+        // definition (and any earlier `--assertion` of the same label) already declared, rather
+        // than appending a second assertion under the same label
+        for assertion in &self.assertions {
+            let data = match manifest.find_assertion::<serde_json::Value>(&assertion.label) {
+                Ok(existing) => merge_assertion_data(&assertion.label, existing, assertion.json.clone())?,
+                Err(_) => assertion.json.clone(),
+            };
+
+            manifest
+                .add_assertion(&assertion.label, &data)
+                .with_context(|| format!("Adding assertion `{}`", assertion.label))?;
+        }
+
         // add claim_tool generator so we know this was created using this tool
         let tool_generator = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
         manifest.claim_generator = if manifest.claim_generator.starts_with("c2pa/") {
@@ -263,12 +527,14 @@ impl Sign {
     }
 
     // Validates input and output paths for conflicts and returns whether the output is
-    // a file or a folder.
-    fn validate(&self) -> Result<bool> {
+    // a file or a folder. `inputs` is the already-expanded set of concrete files (glob
+    // patterns and directories may expand a single CLI argument into several of them, which
+    // forces the same folder-output rules as passing several paths directly).
+    fn validate(&self, inputs: &[ExpandedInput]) -> Result<bool> {
         let num_outputs = if self.sidecar {
-            self.paths.len() * 2
+            inputs.len() * 2
         } else {
-            self.paths.len()
+            inputs.len()
         };
 
         // These restrictions allow a file or folder to be specified as output if there is only one input. If
@@ -283,9 +549,8 @@ impl Sign {
             (true, true, 2..) => {
                 if !self.force {
                     let mut exists = 0;
-                    for path in &self.paths {
-                        // A glob always returns a file path, so it's safe to unwrap.
-                        let mut output = self.output.join(path.file_name().unwrap());
+                    for input in inputs {
+                        let mut output = self.output.join(&input.relative);
                         if output.exists() {
                             exists += 1;
                             warn!("Output path `{}` already exists", output.display());
@@ -323,8 +588,7 @@ impl Sign {
             // the file doesn't exist in the output.
             (true, true, 1) => {
                 if !self.force {
-                    // A glob always returns a file path, so it's safe to unwrap.
-                    let output = self.output.join(self.paths[0].file_name().unwrap());
+                    let output = self.output.join(&inputs[0].relative);
                     if output.exists() {
                         bail!(
                             "Output path `{}` already exists use `--force` to overwrite",
@@ -345,7 +609,7 @@ impl Sign {
             (false, false, 1) => {
                 // TODO: this will be removed eventually, see https://github.com/contentauth/c2patool/issues/150
                 if !self.sidecar {
-                    let input_ext = ext_normal(&self.paths[0]);
+                    let input_ext = ext_normal(&inputs[0].path);
                     let output_ext = ext_normal(&self.output);
                     if input_ext != output_ext {
                         bail!("Manifest cannot be embedded if extensions do not match {}≠{}, specify `--sidecar` to sidecar the manifest", input_ext, output_ext);
@@ -364,6 +628,69 @@ impl Sign {
     }
 }
 
+// Combines an `--assertion` value with an already-present assertion of the same label instead
+// of letting `add_assertion` overwrite it outright. `c2pa.actions`/`c2pa.actions.v2` concatenate
+// the `actions` arrays so a manifest definition's actions survive alongside injected ones;
+// everything else (`stds.exif`, `stds.schema-org.CreativeWork`, and custom labels) is a JSON
+// object, so the two are shallow-merged with `incoming` winning on conflicting keys.
+fn merge_assertion_data(
+    label: &str,
+    existing: serde_json::Value,
+    incoming: serde_json::Value,
+) -> Result<serde_json::Value> {
+    match label {
+        "c2pa.actions" | "c2pa.actions.v2" => {
+            let mut existing: Actions = serde_json::from_value(existing)
+                .with_context(|| format!("Parsing existing `{label}` assertion"))?;
+            let incoming: Actions = serde_json::from_value(incoming)
+                .with_context(|| format!("Parsing incoming `{label}` assertion"))?;
+            existing.actions.extend(incoming.actions);
+            serde_json::to_value(existing)
+                .with_context(|| format!("Serializing merged `{label}` assertion"))
+        }
+        _ => match (existing, incoming) {
+            (serde_json::Value::Object(mut existing), serde_json::Value::Object(incoming)) => {
+                existing.extend(incoming);
+                Ok(serde_json::Value::Object(existing))
+            }
+            (_, incoming) => Ok(incoming),
+        },
+    }
+}
+
+// Returns true if `pattern` contains glob metacharacters and should be expanded rather than
+// treated as a literal path. Only `*`/`?` trigger expansion: a bare `[` is common in literal
+// filenames (e.g. `IMG_001[edited].jpg`) and, unlike `*`/`?`, silently expands to zero matches
+// for that argument if it doesn't happen to form a valid/matching character class.
+fn is_glob_pattern(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+// The portion of a glob pattern's path before its first wildcard component, used as the base
+// that expanded matches are made relative to when preserving subdirectory structure.
+fn glob_base_dir(pattern: &Path) -> PathBuf {
+    let mut base = PathBuf::new();
+    for component in pattern.components() {
+        if is_glob_pattern(&component.as_os_str().to_string_lossy()) {
+            break;
+        }
+        base.push(component);
+    }
+    base
+}
+
+// Recognized embeddable/sidecar-able asset extensions. Glob and directory expansion skip
+// anything else (READMEs, `.DS_Store`, etc.) rather than pushing it into the per-file sign loop
+// to fail individually; a literal path given directly on the command line is never filtered.
+const ASSET_EXTENSIONS: &[&str] = &[
+    "jpg", "png", "tif", "heic", "heif", "avif", "webp", "gif", "svg", "mp4", "mov", "m4a", "mp3",
+    "wav", "pdf", "c2pa",
+];
+
+fn is_asset_file(path: &Path) -> bool {
+    ASSET_EXTENSIONS.contains(&ext_normal(path).as_str())
+}
+
 // normalize extensions so we can compare them
 fn ext_normal(path: &Path) -> String {
     let ext = path
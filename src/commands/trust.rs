@@ -0,0 +1,333 @@
+// Copyright 2022 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use log::info;
+use reqwest::Url;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::commands::InputSource;
+
+/// Manage the persistent local trust store of anchor certificates, used as a fallback for
+/// `--trust-anchors` when it isn't given on the command line. `--trust-config`/`--allowed-list`
+/// are not persisted by this store and must still be passed explicitly (or via
+/// `C2PATOOL_TRUST_CONFIG`/`C2PATOOL_TRUST_ALLOWED_LIST`) on every invocation.
+#[derive(Debug, Parser)]
+pub struct TrustCmd {
+    #[clap(subcommand)]
+    pub command: TrustCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TrustCommand {
+    /// Add a trust anchor (PEM file, inline PEM, or URL) to the local trust store.
+    Add {
+        /// Path to a .pem file, a raw PEM string, or a URL to fetch the anchor from.
+        pem_or_url: String,
+
+        /// Friendly name to store alongside the anchor, defaults to the certificate subject.
+        #[clap(long)]
+        name: Option<String>,
+    },
+    /// List the anchors currently stored in the local trust store.
+    Ls,
+    /// Remove an anchor from the local trust store by name or fingerprint.
+    Rm {
+        /// Name or SHA-256 fingerprint of the anchor to remove.
+        name_or_fingerprint: String,
+    },
+}
+
+impl TrustCmd {
+    pub fn execute(&self) -> Result<()> {
+        let store = TrustStore::open()?;
+        match &self.command {
+            TrustCommand::Add { pem_or_url, name } => store.add(pem_or_url, name.as_deref()),
+            TrustCommand::Ls => store.ls(),
+            TrustCommand::Rm {
+                name_or_fingerprint,
+            } => store.rm(name_or_fingerprint),
+        }
+    }
+}
+
+/// A single anchor persisted in the trust store, alongside the metadata shown by `trust ls`.
+struct StoredAnchor {
+    name: String,
+    fingerprint: String,
+    subject: String,
+    issuer: String,
+    not_after: String,
+    pem: String,
+}
+
+/// On-disk persistent trust store, rooted in the platform config dir (e.g.
+/// `~/.config/c2patool/trust` on Linux, `~/Library/Application Support/c2patool/trust` on
+/// macOS). Holds a single `anchors.pem` bundle; each anchor's friendly name is recorded as a
+/// `# name: ... fingerprint: ...` comment directly above its certificate rather than in a
+/// separate index. Trust config/allowed-list are not stored here.
+pub struct TrustStore {
+    dir: PathBuf,
+}
+
+impl TrustStore {
+    /// Path to the store's `anchors.pem`, suitable for passing straight to the trust reader
+    /// that also accepts `--trust-anchors`.
+    pub fn anchors_path(&self) -> PathBuf {
+        self.dir.join("anchors.pem")
+    }
+
+    /// Opens (creating if necessary) the local trust store directory.
+    pub fn open() -> Result<Self> {
+        let dir = dirs::config_dir()
+            .context("Could not determine the platform config directory")?
+            .join("c2patool")
+            .join("trust");
+
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Creating trust store directory `{}`", dir.display()))?;
+
+        Ok(Self { dir })
+    }
+
+    /// Returns `Some(path)` to the store's `anchors.pem` if it exists and is non-empty, so
+    /// `load_trust_settings` can fall back to it when no explicit `--trust-anchors` is given.
+    pub fn anchors_path_if_present(&self) -> Option<PathBuf> {
+        let path = self.anchors_path();
+        // `rm` rewrites `anchors.pem` in place rather than deleting it, so removing the last
+        // anchor leaves a 0-byte file behind; treat that the same as absent so callers don't
+        // load an empty trust-anchors override in place of falling back to the C2PA default.
+        let is_non_empty = fs::metadata(&path).map(|meta| meta.len() > 0).unwrap_or(false);
+        is_non_empty.then_some(path)
+    }
+
+    fn add(&self, pem_or_url: &str, name: Option<&str>) -> Result<()> {
+        let pem = self.resolve_pem(pem_or_url)?;
+        // `pem_or_url` may resolve to a bundle of several certificates (a multi-cert file or
+        // URL); store each as its own anchor entry rather than one entry covering the whole
+        // blob, so a later `load_anchors()` (which always splits on `-----END CERTIFICATE-----`)
+        // reports the same anchors `add` just did, and `rm` of one doesn't silently drop the
+        // rest.
+        let anchors = parse_anchors(&pem, name)?;
+
+        let anchors_path = self.anchors_path();
+        let mut bundle = if anchors_path.exists() {
+            fs::read_to_string(&anchors_path)?
+        } else {
+            String::new()
+        };
+
+        for anchor in &anchors {
+            if bundle.contains(&anchor.fingerprint) {
+                bail!(
+                    "Anchor `{}` ({}) is already in the trust store",
+                    anchor.name,
+                    anchor.fingerprint
+                );
+            }
+
+            if !bundle.is_empty() && !bundle.ends_with('\n') {
+                bundle.push('\n');
+            }
+            bundle.push_str(&format!(
+                "# name: {} fingerprint: {}\n",
+                anchor.name, anchor.fingerprint
+            ));
+            bundle.push_str(&anchor.pem);
+            if !bundle.ends_with('\n') {
+                bundle.push('\n');
+            }
+        }
+
+        fs::write(&anchors_path, bundle)
+            .with_context(|| format!("Writing trust store at `{}`", anchors_path.display()))?;
+
+        for anchor in &anchors {
+            info!(
+                "Added anchor `{}` ({}) to the trust store",
+                anchor.name, anchor.fingerprint
+            );
+        }
+
+        Ok(())
+    }
+
+    fn ls(&self) -> Result<()> {
+        for anchor in self.load_anchors()? {
+            println!(
+                "{}\n  fingerprint: {}\n  subject:     {}\n  issuer:      {}\n  not after:   {}",
+                anchor.name, anchor.fingerprint, anchor.subject, anchor.issuer, anchor.not_after
+            );
+        }
+
+        Ok(())
+    }
+
+    fn rm(&self, name_or_fingerprint: &str) -> Result<()> {
+        let anchors = self.load_anchors()?;
+        let (keep, removed): (Vec<_>, Vec<_>) = anchors
+            .into_iter()
+            .partition(|a| a.name != name_or_fingerprint && a.fingerprint != name_or_fingerprint);
+
+        if removed.is_empty() {
+            bail!("No anchor named or fingerprinted `{name_or_fingerprint}` in the trust store");
+        }
+
+        let mut bundle = String::new();
+        for anchor in &keep {
+            bundle.push_str(&format!(
+                "# name: {} fingerprint: {}\n",
+                anchor.name, anchor.fingerprint
+            ));
+            bundle.push_str(&anchor.pem);
+            if !bundle.ends_with('\n') {
+                bundle.push('\n');
+            }
+        }
+
+        fs::write(self.anchors_path(), bundle)?;
+
+        for anchor in &removed {
+            info!("Removed anchor `{}` ({})", anchor.name, anchor.fingerprint);
+        }
+
+        Ok(())
+    }
+
+    fn resolve_pem(&self, pem_or_url: &str) -> Result<String> {
+        if Path::new(pem_or_url).is_file() {
+            return Ok(fs::read_to_string(pem_or_url)?);
+        }
+
+        if pem_or_url.contains("BEGIN CERTIFICATE") {
+            return Ok(pem_or_url.to_string());
+        }
+
+        let url: Url = pem_or_url
+            .parse()
+            .context("`pem_or_url` is neither an existing file, inline PEM, nor a valid URL")?;
+
+        InputSource::from_path_or_url(None, Some(&url))
+            .context("resolving trust anchor URL")?
+            .resolve()
+    }
+
+    fn load_anchors(&self) -> Result<Vec<StoredAnchor>> {
+        let anchors_path = self.anchors_path();
+        if !anchors_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let bundle = fs::read_to_string(&anchors_path)?;
+        let mut anchors = Vec::new();
+        let mut pending_name = None;
+
+        for block in split_pem_blocks(&bundle) {
+            match block {
+                PemBlock::Comment(name) => pending_name = Some(name),
+                PemBlock::Pem(pem) => {
+                    anchors.push(parse_anchor(&pem, pending_name.take().as_deref())?)
+                }
+            }
+        }
+
+        Ok(anchors)
+    }
+}
+
+enum PemBlock {
+    Comment(String),
+    Pem(String),
+}
+
+/// Splits a concatenated PEM bundle (as written by `trust add`) back into its `# name: ...
+/// fingerprint: ...` comment lines and the certificate blocks that follow them. The fingerprint
+/// in the comment is only there so a later `add` can detect the anchor is already present
+/// without reparsing every certificate; it's recomputed from the DER when loading, not trusted.
+fn split_pem_blocks(bundle: &str) -> Vec<PemBlock> {
+    let mut blocks = Vec::new();
+    let mut current = String::new();
+
+    for line in bundle.lines() {
+        if let Some(rest) = line.strip_prefix("# name: ") {
+            let name = rest.split(" fingerprint: ").next().unwrap_or(rest);
+            if !current.trim().is_empty() {
+                blocks.push(PemBlock::Pem(current.clone()));
+                current.clear();
+            }
+            blocks.push(PemBlock::Comment(name.to_string()));
+            continue;
+        }
+
+        current.push_str(line);
+        current.push('\n');
+
+        if line.trim() == "-----END CERTIFICATE-----" {
+            blocks.push(PemBlock::Pem(current.clone()));
+            current.clear();
+        }
+    }
+
+    blocks
+}
+
+fn parse_anchor(pem: &str, name: Option<&str>) -> Result<StoredAnchor> {
+    let der = pem::parse(pem).context("Parsing PEM anchor")?;
+    anchor_from_der(&der, name)
+}
+
+/// Parses every certificate block out of `pem` (which may be a single certificate or a bundle of
+/// several, e.g. resolved from a multi-cert file or URL), returning one `StoredAnchor` per
+/// certificate so `add` persists and reports each individually instead of treating the whole
+/// bundle as a single anchor under one name/fingerprint.
+fn parse_anchors(pem: &str, name: Option<&str>) -> Result<Vec<StoredAnchor>> {
+    let blocks = pem::parse_many(pem).context("Parsing PEM anchor(s)")?;
+    if blocks.is_empty() {
+        bail!("No PEM certificates found in input");
+    }
+
+    blocks
+        .iter()
+        .map(|der| anchor_from_der(der, name))
+        .collect()
+}
+
+fn anchor_from_der(der: &pem::Pem, name: Option<&str>) -> Result<StoredAnchor> {
+    let (_, cert) =
+        X509Certificate::from_der(der.contents()).context("Parsing X.509 trust anchor")?;
+
+    let subject = cert.subject().to_string();
+    let fingerprint = hex_fingerprint(der.contents());
+
+    Ok(StoredAnchor {
+        name: name.map(str::to_string).unwrap_or_else(|| subject.clone()),
+        fingerprint,
+        subject,
+        issuer: cert.issuer().to_string(),
+        not_after: cert.validity().not_after.to_string(),
+        pem: pem::encode(der),
+    })
+}
+
+fn hex_fingerprint(der: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(der)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
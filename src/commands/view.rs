@@ -0,0 +1,71 @@
+// Copyright 2022 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use c2pa::Reader;
+use clap::{Args, Parser, Subcommand};
+
+use crate::commands::{load_trust_settings, Trust};
+
+/// Inspect the manifest store associated with an asset, without signing anything.
+#[derive(Debug, Parser)]
+pub struct View {
+    #[clap(subcommand)]
+    pub command: ViewCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ViewCommand {
+    /// Print the manifest store embedded in (or remotely referenced by) an asset, as JSON.
+    Manifest(ViewManifest),
+}
+
+#[derive(Debug, Args)]
+pub struct ViewManifest {
+    /// Path to the asset to read the manifest store from.
+    pub path: PathBuf,
+
+    #[clap(flatten)]
+    pub trust: Trust,
+
+    /// Serve remote trust anchors/config only from the local HTTP cache, erroring if nothing is
+    /// cached.
+    #[clap(long, conflicts_with = "no_cache")]
+    pub offline: bool,
+
+    /// Always refetch remote trust anchors/config instead of reusing the local HTTP cache.
+    #[clap(long)]
+    pub no_cache: bool,
+}
+
+impl View {
+    pub fn execute(&self) -> Result<()> {
+        match &self.command {
+            ViewCommand::Manifest(manifest) => manifest.execute(),
+        }
+    }
+}
+
+impl ViewManifest {
+    fn execute(&self) -> Result<()> {
+        load_trust_settings(&self.trust, self.offline, self.no_cache)?;
+
+        let reader = Reader::from_file(&self.path)
+            .with_context(|| format!("Reading manifest store from `{}`", self.path.display()))?;
+
+        println!("{}", reader.json());
+
+        Ok(())
+    }
+}
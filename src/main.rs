@@ -0,0 +1,49 @@
+// Copyright 2022 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+mod cache;
+mod callback_signer;
+mod commands;
+mod signer;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use commands::{Sign, TrustCmd, View};
+
+#[derive(Debug, Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[clap(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Sign an asset with a manifest.
+    Sign(Sign),
+    /// Manage the persistent local trust store used as a fallback for `--trust-anchors`.
+    Trust(TrustCmd),
+    /// Inspect the manifest store associated with an asset.
+    View(View),
+}
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let cli = Cli::parse();
+    match &cli.command {
+        Commands::Sign(sign) => sign.execute(),
+        Commands::Trust(trust) => trust.execute(),
+        Commands::View(view) => view.execute(),
+    }
+}
@@ -0,0 +1,104 @@
+mod test_utils;
+
+use insta_cmd::assert_cmd_snapshot;
+use test_utils::{cmd, fixture_path};
+
+// `--assertion` is parsed up front by clap (via `AssertionArg::from_str`), before `sign` does
+// anything with --manifest/--output, so these only need placeholder values for the other
+// required arguments to exercise the parsing/validation path in isolation.
+
+#[test]
+fn test_unrecognized_assertion_label_is_rejected() {
+    // 0 dots: neither a known C2PA label nor namespaced-looking, so this must be rejected by the
+    // `--assertion` label check itself, during clap parsing, before --manifest is ever read.
+    // `--manifest` below points at something that isn't valid manifest JSON specifically so the
+    // snapshot can't pass for the wrong reason (a manifest-parsing failure instead of a rejected
+    // label).
+    let output = cmd()
+        .arg("sign")
+        .arg(fixture_path("sample.jpg"))
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg("out.jpg")
+        .arg("--assertion")
+        .arg(r#"not-a-known-label={"x":1}"#)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("not-a-known-label") && stderr.contains("not a known C2PA assertion label"),
+        "expected the label to be rejected as unrecognized, got: {stderr}"
+    );
+}
+
+#[test]
+fn test_assertion_label_with_empty_namespace_segment_is_rejected() {
+    // 2 dots, but the middle segment is empty, so this must not be mistaken for a namespaced
+    // custom label like `org.example.foo`.
+    let output = cmd()
+        .arg("sign")
+        .arg(fixture_path("sample.jpg"))
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg("out.jpg")
+        .arg("--assertion")
+        .arg(r#"org..foo={"x":1}"#)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("org..foo") && stderr.contains("not a known C2PA assertion label"),
+        "expected the label to be rejected as unrecognized, got: {stderr}"
+    );
+}
+
+#[test]
+fn test_custom_namespaced_assertion_label_is_accepted() {
+    // Namespaced labels (2+ dots) that aren't one of the known C2PA labels are allowed through
+    // without shape validation, since there's no known type to validate custom data against.
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .arg(fixture_path("sample.jpg"))
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg("out.jpg")
+        .arg("--assertion")
+        .arg(r#"org.example.foo={"x":1}"#));
+}
+
+#[test]
+fn test_malformed_actions_assertion_is_rejected() {
+    // `actions` must be an array; this fails c2pa::assertions::Actions deserialization rather
+    // than passing CLI parsing and only failing deep inside the SDK during signing.
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .arg(fixture_path("sample.jpg"))
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg("out.jpg")
+        .arg("--assertion")
+        .arg(r#"c2pa.actions={"actions":"not-an-array"}"#));
+}
+
+#[test]
+fn test_thumbnail_labels_are_no_longer_known_assertion_labels() {
+    // c2pa.thumbnail.claim/ingredient carry binary image data, which --assertion's JSON-only
+    // form can't express, so they're treated like any other unrecognized label.
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .arg(fixture_path("sample.jpg"))
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg("out.jpg")
+        .arg("--assertion")
+        .arg(r#"c2pa.thumbnail.claim={"x":1}"#));
+}
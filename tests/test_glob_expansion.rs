@@ -0,0 +1,97 @@
+mod test_utils;
+
+use std::fs;
+
+use insta_cmd::assert_cmd_snapshot;
+use test_utils::{cmd, fixture_path};
+
+#[test]
+fn test_directory_expansion_skips_non_asset_files() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::copy(fixture_path("sample.jpg"), dir.path().join("sample.jpg")).unwrap();
+    fs::write(dir.path().join("README.txt"), "not an asset").unwrap();
+
+    let output_dir = tempfile::tempdir().unwrap();
+
+    // Only `sample.jpg` should be expanded into the sign loop; if `README.txt` were also
+    // expanded this would report 2 attempted assets instead of 1.
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .arg(dir.path())
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg")) // any file; manifest parsing fails before this matters
+        .arg("--output")
+        .arg(output_dir.path()));
+}
+
+#[test]
+fn test_directory_of_only_non_asset_files_errors_clearly() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("README.txt"), "not an asset").unwrap();
+
+    let output_dir = tempfile::tempdir().unwrap();
+
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .arg(dir.path())
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg(output_dir.path()));
+}
+
+#[test]
+fn test_recursive_glob_preserves_subdirectory_structure_under_output() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::copy(fixture_path("sample.jpg"), dir.path().join("top.jpg")).unwrap();
+    fs::create_dir_all(dir.path().join("sub/deep")).unwrap();
+    fs::copy(fixture_path("sample.jpg"), dir.path().join("sub/a.jpg")).unwrap();
+    fs::copy(fixture_path("sample.jpg"), dir.path().join("sub/deep/b.jpg")).unwrap();
+    fs::write(dir.path().join("sub/readme.txt"), "not an asset").unwrap();
+
+    let output_dir = tempfile::tempdir().unwrap();
+    let pattern = format!("{}/**/*.jpg", dir.path().display());
+
+    // The manifest isn't valid JSON, so every matched asset fails to sign; --keep-going makes
+    // sure all of them are still attempted (and so have their output parent dir created) rather
+    // than aborting after the first.
+    let result = cmd()
+        .arg("sign")
+        .arg(&pattern)
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg(output_dir.path())
+        .arg("--keep-going")
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    assert_eq!(
+        stderr.matches("Failed to sign asset").count(),
+        3,
+        "expected exactly the 3 .jpg assets to be attempted (not sub/readme.txt), got: {stderr}"
+    );
+
+    // `relative` strips the pattern's base dir (everything before the first `**`/`*` component),
+    // so each match's output parent should land at the matching subpath under `--output`.
+    assert!(output_dir.path().is_dir());
+    assert!(output_dir.path().join("sub").is_dir());
+    assert!(output_dir.path().join("sub/deep").is_dir());
+}
+
+#[test]
+fn test_bracket_in_literal_filename_is_not_treated_as_a_glob() {
+    // `img[1].jpg` doesn't exist, but it also isn't a glob pattern (no `*`/`?`): it should be
+    // signed as a single literal input and fail while attempting that one asset, not silently
+    // expand to zero matches via `glob::glob` the way a bare `[` used to.
+    let output = tempfile::tempdir().unwrap().path().join("out.jpg");
+
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .arg("img[1].jpg")
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg(output));
+}
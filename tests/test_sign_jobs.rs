@@ -0,0 +1,55 @@
+mod test_utils;
+
+use std::fs;
+
+use insta_cmd::assert_cmd_snapshot;
+use test_utils::{cmd, fixture_path};
+
+// Three inputs that all fail to sign (the manifest argument below isn't valid manifest JSON),
+// used to observe whether --keep-going let every one of them be attempted.
+fn three_broken_inputs(dir: &std::path::Path) -> Vec<std::path::PathBuf> {
+    (0..3)
+        .map(|i| {
+            let path = dir.join(format!("{i}.jpg"));
+            fs::copy(fixture_path("sample.jpg"), &path).unwrap();
+            path
+        })
+        .collect()
+}
+
+#[test]
+fn test_without_keep_going_aborts_after_the_first_failure() {
+    let input_dir = tempfile::tempdir().unwrap();
+    let inputs = three_broken_inputs(input_dir.path());
+    let output_dir = tempfile::tempdir().unwrap();
+
+    // `--jobs 1` makes this deterministic: the pool processes inputs strictly in order, so
+    // without --keep-going exactly 1 of the 3 should ever be attempted before aborting.
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .args(&inputs)
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg(output_dir.path())
+        .arg("--jobs")
+        .arg("1"));
+}
+
+#[test]
+fn test_keep_going_attempts_every_input_despite_earlier_failures() {
+    let input_dir = tempfile::tempdir().unwrap();
+    let inputs = three_broken_inputs(input_dir.path());
+    let output_dir = tempfile::tempdir().unwrap();
+
+    assert_cmd_snapshot!(cmd()
+        .arg("sign")
+        .args(&inputs)
+        .arg("--manifest")
+        .arg(fixture_path("trust/store.cfg"))
+        .arg("--output")
+        .arg(output_dir.path())
+        .arg("--jobs")
+        .arg("1")
+        .arg("--keep-going"));
+}
@@ -90,4 +90,151 @@ fn test_load_trust_from_untrusted_url_env() {
         .env("C2PATOOL_TRUST_CONFIG", server.url("/trust/store.cfg")));
 
     mocks.iter().for_each(|m| m.assert());
+}
+
+#[test]
+fn test_trust_add_ls_rm() {
+    let config_dir = tempfile::tempdir().unwrap();
+
+    let add = cmd()
+        .arg("trust")
+        .arg("add")
+        .arg(fixture_path("trust/anchors.pem"))
+        .arg("--name")
+        .arg("test-anchor")
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .output()
+        .unwrap();
+    assert!(add.status.success());
+
+    assert_cmd_snapshot!(cmd()
+        .arg("trust")
+        .arg("ls")
+        .env("XDG_CONFIG_HOME", config_dir.path()));
+
+    assert_cmd_snapshot!(cmd()
+        .arg("trust")
+        .arg("rm")
+        .arg("test-anchor")
+        .env("XDG_CONFIG_HOME", config_dir.path()));
+}
+
+#[test]
+fn test_view_uses_stored_trust_anchor() {
+    let config_dir = tempfile::tempdir().unwrap();
+
+    let add = cmd()
+        .arg("trust")
+        .arg("add")
+        .arg(fixture_path("trust/anchors.pem"))
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .output()
+        .unwrap();
+    assert!(add.status.success());
+
+    // No `--trust-anchors` here: this should fall back to the anchor just added to the store.
+    assert_cmd_snapshot!(cmd()
+        .arg("view")
+        .arg("manifest")
+        .arg(test_img_path())
+        .arg("--trust-config")
+        .arg(fixture_path("trust/store.cfg"))
+        .env("XDG_CONFIG_HOME", config_dir.path()));
+}
+
+#[test]
+fn test_view_rejects_when_stored_trust_anchor_does_not_match() {
+    let config_dir = tempfile::tempdir().unwrap();
+
+    let add = cmd()
+        .arg("trust")
+        .arg("add")
+        .arg(fixture_path("trust/no-match.pem"))
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .output()
+        .unwrap();
+    assert!(add.status.success());
+
+    // The stored anchor doesn't match the asset's signer, so this should fail the same way
+    // `test_load_trust_from_untrusted_file` does with an explicit `--trust-anchors`.
+    assert_cmd_snapshot!(cmd()
+        .arg("view")
+        .arg("manifest")
+        .arg(test_img_path())
+        .arg("--trust-config")
+        .arg(fixture_path("trust/store.cfg"))
+        .env("XDG_CONFIG_HOME", config_dir.path()));
+}
+
+#[test]
+fn test_trust_add_splits_multi_cert_bundle_into_separate_anchors() {
+    let config_dir = tempfile::tempdir().unwrap();
+
+    // `bundle.pem` concatenates `anchors.pem` and `no-match.pem`; `add` must persist and report
+    // both certificates as their own anchors rather than one entry covering the whole bundle, so
+    // `ls` (a fresh process re-splitting `anchors.pem`) agrees with what `add` just printed, and
+    // removing one by name leaves the other in the store.
+    let add = cmd()
+        .arg("trust")
+        .arg("add")
+        .arg(fixture_path("trust/bundle.pem"))
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .output()
+        .unwrap();
+    assert!(add.status.success());
+    let stderr = String::from_utf8_lossy(&add.stderr);
+    assert_eq!(
+        stderr.matches("Added anchor").count(),
+        2,
+        "expected both bundled certificates to be reported as added, got: {stderr}"
+    );
+
+    let ls = cmd()
+        .arg("trust")
+        .arg("ls")
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .output()
+        .unwrap();
+    assert!(ls.status.success());
+    let stdout = String::from_utf8_lossy(&ls.stdout);
+    assert_eq!(
+        stdout.matches("fingerprint:").count(),
+        2,
+        "expected ls to list both bundled certificates as separate anchors, got: {stdout}"
+    );
+
+    let rm = cmd()
+        .arg("trust")
+        .arg("rm")
+        .arg("c2patool test trust anchor")
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .output()
+        .unwrap();
+    assert!(rm.status.success());
+
+    let ls_after_rm = cmd()
+        .arg("trust")
+        .arg("ls")
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .output()
+        .unwrap();
+    assert!(ls_after_rm.status.success());
+    let stdout_after_rm = String::from_utf8_lossy(&ls_after_rm.stdout);
+    assert_eq!(
+        stdout_after_rm.matches("fingerprint:").count(),
+        1,
+        "expected only the other bundled certificate to remain, got: {stdout_after_rm}"
+    );
+    assert!(stdout_after_rm.contains("c2patool test non-matching anchor"));
+}
+
+#[test]
+fn test_trust_rm_unknown_anchor_fails() {
+    let config_dir = tempfile::tempdir().unwrap();
+
+    assert_cmd_snapshot!(cmd()
+        .arg("trust")
+        .arg("rm")
+        .arg("does-not-exist")
+        .env("XDG_CONFIG_HOME", config_dir.path()));
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use std::{fs, path::PathBuf};
+
+use assert_cmd::Command;
+use httpmock::{Method::GET, Mock, MockServer};
+
+/// Path to a file under `tests/fixtures`.
+pub fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// Path to the sample asset read by the trust and view tests.
+pub fn test_img_path() -> PathBuf {
+    fixture_path("sample.jpg")
+}
+
+/// A `Command` running the `c2patool` binary under test, with its own scratch config dir and
+/// HTTP cache dir so `trust`/`sign`/`view` never read or write the real
+/// `~/.config/c2patool/trust` or `~/.cache/c2patool/http` on the host. Both tempdirs are leaked
+/// (never cleaned up) since they only need to outlive this one process invocation; tests that
+/// drive the trust store or HTTP cache across several `cmd()` calls override these with their
+/// own longer-lived `tempfile::tempdir()` via `.env("XDG_CONFIG_HOME" / "XDG_CACHE_HOME", ...)`.
+pub fn cmd() -> Command {
+    let mut command = Command::cargo_bin("c2patool").unwrap();
+    command
+        .env("XDG_CONFIG_HOME", tempfile::tempdir().unwrap().into_path())
+        .env("XDG_CACHE_HOME", tempfile::tempdir().unwrap().into_path());
+    command
+}
+
+/// Serves the given trust anchor/config fixtures at `/trust/anchors.pem` and `/trust/store.cfg`
+/// on `server`, mirroring the paths used by the local-file variants of the same tests.
+pub fn create_mock_server<'a>(
+    server: &'a MockServer,
+    anchors_fixture: &str,
+    config_fixture: &str,
+) -> Vec<Mock<'a>> {
+    let anchors = fs::read_to_string(fixture_path(anchors_fixture)).unwrap();
+    let config = fs::read_to_string(fixture_path(config_fixture)).unwrap();
+
+    vec![
+        server.mock(|when, then| {
+            when.method(GET).path("/trust/anchors.pem");
+            then.status(200).body(anchors);
+        }),
+        server.mock(|when, then| {
+            when.method(GET).path("/trust/store.cfg");
+            then.status(200).body(config);
+        }),
+    ]
+}